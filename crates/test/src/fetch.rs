@@ -1,13 +1,17 @@
 //! Helpers to provide mock HTTP responses for test fixtures.
 
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
 use anyhow::anyhow;
 use bytes::Bytes;
 use http::header::HOST;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Configuration for mocking fetch requests.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(default)]
 pub struct Fetch {
     /// Authority (host) to match for mock fetch requests.
@@ -20,20 +24,86 @@ pub struct Fetch {
     /// Defaults to GET.
     pub method: Method,
 
-    /// Path to match for mock fetch requests, not including query parameters.
+    /// Path template to match for mock fetch requests, not including query
+    /// parameters.
+    ///
+    /// Literal segments must match exactly, a `{name}` segment matches any
+    /// single non-empty segment and captures it under `name`, and a trailing
+    /// `*` segment matches zero or more remaining segments. For example
+    /// `/users/{id}/orders/*` matches `/users/42/orders/2024/11`, capturing
+    /// `id -> "42"`.
     ///
     /// Defaults to "/".
     pub path: String,
 
-    /// String to uniquely identify a fetch request.
+    /// Query string to match for mock fetch requests.
     ///
-    /// This simulates a query string or body content to differentiate requests
-    /// so a serialized representation of those could be used in test fixtures,
-    /// or some abbreviated identifier.
+    /// Compared to the request's query string as an order-insensitive set of
+    /// key/value pairs, so `a=1&b=2` matches a request with `b=2&a=1`.
     pub request: Option<String>,
 
-    /// Expected response if all the other fields match.
-    pub response: Response,
+    /// Headers that must be present on the incoming request for this fetch to
+    /// match.
+    ///
+    /// Header names are compared case-insensitively; values must match
+    /// exactly. Headers not listed here are ignored, so a fixture only needs
+    /// to name the headers it cares about (e.g. `Authorization`).
+    pub headers: Option<BTreeMap<String, String>>,
+
+    /// Expected request body to match structurally, rather than by exact
+    /// bytes.
+    ///
+    /// Only checked by `Fetcher::fetch_bytes` and
+    /// `Fetcher::fetch_bytes_with_params`, which read the request body;
+    /// `Fetcher::fetch` and `Fetcher::fetch_with_params` treat a fetch with
+    /// `match_body` set as never matching, since they have no body to
+    /// compare against.
+    pub match_body: Option<MatchBody>,
+
+    /// Minimum number of times this fetch must be matched, checked by
+    /// `Fetcher::verify`.
+    ///
+    /// Defaults to no minimum.
+    pub min_calls: Option<usize>,
+
+    /// Maximum number of times this fetch may be matched, checked by
+    /// `Fetcher::verify`.
+    ///
+    /// Defaults to no maximum.
+    pub max_calls: Option<usize>,
+
+    /// Simulated transport-level failure to return instead of a response
+    /// when this fetch matches.
+    ///
+    /// Defaults to no fault, i.e. the matched response is always returned.
+    pub fault: Option<Fault>,
+
+    /// Response(s) returned when all the other fields match.
+    ///
+    /// A sequence advances through its entries on each match, repeating the
+    /// last entry once exhausted, so retry/backoff and pagination can be
+    /// exercised with a single fixture.
+    pub response: Responses,
+
+    /// Number of times this fetch has been matched so far.
+    ///
+    /// Not part of the fixture format; tracked at runtime to advance
+    /// sequential responses and to support `Fetcher::verify`.
+    #[serde(skip)]
+    call_count: AtomicUsize,
+}
+
+impl Fetch {
+    /// Create a new `Fetch` with default values for every field.
+    ///
+    /// `Fetch` has a private `call_count` field to track runtime state, so
+    /// struct-update syntax (`Fetch { authority: ..., ..Default::default() }`)
+    /// doesn't compile outside this module. Prefer `Fetch::new()` followed by
+    /// setting the public fields you need, or deserializing a fixture.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 /// Default implementation for Fetch to fill in unspecified fields from test
@@ -45,11 +115,154 @@ impl Default for Fetch {
             method: Method::GET,
             path: "/".to_string(),
             request: None,
-            response: Response::default(),
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            response: Responses::default(),
+            call_count: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// `AtomicUsize` isn't `Clone`, so this is implemented by hand, starting the
+/// clone's call count fresh rather than copying the original's in-progress
+/// count.
+impl Clone for Fetch {
+    fn clone(&self) -> Self {
+        Self {
+            authority: self.authority.clone(),
+            method: self.method.clone(),
+            path: self.path.clone(),
+            request: self.request.clone(),
+            headers: self.headers.clone(),
+            match_body: self.match_body.clone(),
+            min_calls: self.min_calls,
+            max_calls: self.max_calls,
+            fault: self.fault.clone(),
+            response: self.response.clone(),
+            call_count: AtomicUsize::new(0),
         }
     }
 }
 
+/// A simulated transport-level failure for a `Fetch`, so handlers that
+/// implement timeout handling, circuit breaking, or error recovery can be
+/// exercised without a well-formed response.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct Fault {
+    /// Category of transport failure to simulate.
+    ///
+    /// Defaults to `ConnectionRefused`.
+    pub kind: FaultKind,
+
+    /// Simulated latency before the fault is surfaced.
+    ///
+    /// `Fetcher::fetch` honors this by sleeping synchronously before
+    /// returning the error, so a `Timeout` fault can carry the delay it took
+    /// to time out. Defaults to no delay.
+    pub delay: Option<Duration>,
+}
+
+impl Default for Fault {
+    fn default() -> Self {
+        Self { kind: FaultKind::ConnectionRefused, delay: None }
+    }
+}
+
+/// Category of simulated transport failure, mirroring the distinct error
+/// categories a real fetch client would surface.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum FaultKind {
+    /// The connection was refused before any bytes were exchanged.
+    ConnectionRefused,
+    /// The request exceeded its deadline before a response arrived.
+    Timeout,
+    /// DNS resolution for the authority failed.
+    Dns,
+    /// The TLS handshake failed.
+    Tls,
+}
+
+impl std::fmt::Display for FaultKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ConnectionRefused => "connection refused",
+            Self::Timeout => "request timed out",
+            Self::Dns => "DNS resolution failed",
+            Self::Tls => "TLS handshake failed",
+        })
+    }
+}
+
+impl std::error::Error for FaultKind {}
+
+/// One or more responses for a `Fetch`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Responses {
+    /// A single response returned on every match.
+    Single(Response),
+    /// An ordered sequence of responses. Each match advances to the next
+    /// entry; once exhausted, the last entry repeats.
+    Sequence(Vec<Response>),
+}
+
+impl Responses {
+    /// The response to return for the given (zero-based) call index.
+    fn at(&self, call_index: usize) -> Response {
+        match self {
+            Self::Single(response) => response.clone(),
+            Self::Sequence(responses) => responses
+                .get(call_index)
+                .or_else(|| responses.last())
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for Responses {
+    fn default() -> Self {
+        Self::Single(Response::default())
+    }
+}
+
+impl From<Response> for Responses {
+    fn from(response: Response) -> Self {
+        Self::Single(response)
+    }
+}
+
+/// Expected request body for a `Fetch`, compared structurally rather than by
+/// exact bytes.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct MatchBody {
+    /// Content type describing how to parse the incoming request body before
+    /// comparing it to `value`.
+    ///
+    /// Defaults to `application/json`. `text/plain` compares the body as a
+    /// UTF-8 string against `value`, which must be a JSON string (any other
+    /// `value` never matches); `application/octet-stream` is not supported
+    /// for request matching.
+    pub content_type: ContentType,
+
+    /// Expected body. For JSON this is compared as a `Value` tree
+    /// (order-independent for objects); for form bodies each field is
+    /// compared as a key/multi-value map, where an array value lists a key's
+    /// repeated values.
+    pub value: Value,
+}
+
+impl Default for MatchBody {
+    fn default() -> Self {
+        Self { content_type: ContentType::default(), value: Value::Null }
+    }
+}
+
 /// Supported HTTP verbs (methods) for fetch requests.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub enum Method {
@@ -79,14 +292,156 @@ pub struct Response {
     ///
     /// This is a `Value` that the test is expected to deserialize as needed.
     /// Defaults to an empty string for tests that do not require asserting on
-    /// response body contents.
+    /// response body contents. How it is encoded onto the wire is governed by
+    /// `content_type`.
     pub body: Value,
+
+    /// How `body` is encoded onto the wire, and the `Content-Type` header set
+    /// on the emitted response unless overridden in `headers`.
+    ///
+    /// Defaults to `application/json`.
+    pub content_type: ContentType,
+
+    /// Headers to set on the emitted mock response.
+    ///
+    /// Defaults to no headers. A `Content-Type` entry here overrides the one
+    /// implied by `content_type`.
+    pub headers: BTreeMap<String, String>,
+
+    /// Simulated latency before this response is returned, honored by
+    /// `Fetcher::fetch` sleeping synchronously beforehand.
+    ///
+    /// Defaults to no delay. Unlike `Fault::delay`, this does not produce an
+    /// error; it just simulates a slow but otherwise successful response.
+    pub delay: Option<Duration>,
 }
 
 impl Default for Response {
     fn default() -> Self {
-        Self { status: 200, body: Value::String(String::new()) }
+        Self {
+            status: 200,
+            body: Value::String(String::new()),
+            content_type: ContentType::default(),
+            headers: BTreeMap::new(),
+            delay: None,
+        }
+    }
+}
+
+/// MIME type controlling how a mock `Response::body` is encoded onto the
+/// wire.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub enum ContentType {
+    /// Serialize `body` as JSON. This is the default, matching the prior
+    /// behavior of always JSON-encoding the body.
+    #[serde(rename = "application/json")]
+    #[default]
+    Json,
+    /// Emit `body` verbatim when it is a JSON string; other `Value`s fall
+    /// back to their JSON representation.
+    #[serde(rename = "text/plain")]
+    Text,
+    /// Render an object `body` as `application/x-www-form-urlencoded`.
+    #[serde(rename = "application/x-www-form-urlencoded")]
+    Form,
+    /// Base64-decode a JSON string `body` into raw bytes, for binary
+    /// payloads.
+    #[serde(rename = "application/octet-stream")]
+    Bytes,
+}
+
+impl ContentType {
+    /// The MIME type string for this content type, as set in the
+    /// `Content-Type` header.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Text => "text/plain",
+            Self::Form => "application/x-www-form-urlencoded",
+            Self::Bytes => "application/octet-stream",
+        }
+    }
+}
+
+/// Encode a mock response body according to its content type.
+///
+/// # Errors
+///
+/// Returns an error when `body` cannot be encoded as the requested content
+/// type, e.g. a non-string `body` with `ContentType::Bytes`, or invalid
+/// base64.
+fn encode_body(content_type: &ContentType, body: &Value) -> anyhow::Result<Bytes> {
+    match content_type {
+        ContentType::Json => Ok(Bytes::from(body.to_string())),
+        ContentType::Text => match body {
+            Value::String(text) => Ok(Bytes::from(text.clone())),
+            other => Ok(Bytes::from(other.to_string())),
+        },
+        ContentType::Form => {
+            serde_urlencoded::to_string(body).map(Bytes::from).map_err(anyhow::Error::new)
+        }
+        ContentType::Bytes => match body {
+            Value::String(encoded) => {
+                use base64::Engine;
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded)
+                    .map(Bytes::from)
+                    .map_err(anyhow::Error::new)
+            }
+            _ => Err(anyhow!("response body for content type {} must be a base64 string", content_type.as_str())),
+        },
+    }
+}
+
+/// Check whether an incoming request body structurally matches a fetch's
+/// `match_body` expectation.
+///
+/// Returns `true` when no expectation is set. Returns `false` when an
+/// expectation is set but no body bytes are available to compare against
+/// (i.e. the caller used `Fetcher::fetch` rather than `Fetcher::fetch_bytes`).
+fn body_matches(expected: Option<&MatchBody>, body: Option<&[u8]>) -> bool {
+    let Some(expected) = expected else {
+        return true;
+    };
+    let Some(body) = body else {
+        return false;
+    };
+    match expected.content_type {
+        ContentType::Json => {
+            serde_json::from_slice::<Value>(body).is_ok_and(|actual| actual == expected.value)
+        }
+        ContentType::Form => std::str::from_utf8(body)
+            .is_ok_and(|actual| parse_query(actual) == value_to_query_map(&expected.value)),
+        ContentType::Text => std::str::from_utf8(body)
+            .is_ok_and(|actual| expected.value.as_str() == Some(actual)),
+        ContentType::Bytes => false,
+    }
+}
+
+/// Convert a JSON object `Value` into the same key/multi-value map shape that
+/// `parse_query` produces, so a form-encoded request body can be compared
+/// against a `MatchBody::value` written as a JSON object. An array value
+/// contributes one entry per element, for keys repeated in the form body.
+fn value_to_query_map(value: &Value) -> BTreeMap<String, Vec<String>> {
+    let to_text = |value: &Value| match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    if let Value::Object(object) = value {
+        for (key, value) in object {
+            let values = map.entry(key.clone()).or_default();
+            match value {
+                Value::Array(items) => values.extend(items.iter().map(to_text)),
+                other => values.push(to_text(other)),
+            }
+        }
     }
+    for values in map.values_mut() {
+        values.sort();
+    }
+    map
 }
 
 /// Collection of fetch request configurations that can be used in an Augentic
@@ -107,11 +462,81 @@ impl Fetcher {
     /// Simulate fetching a request by finding a matching fetch configuration
     /// and returning the response.
     ///
+    /// Fetch configurations with `match_body` set never match through this
+    /// method, since it cannot read the request body; use
+    /// `Fetcher::fetch_bytes` for those.
+    ///
     /// # Errors
     ///
     /// Returns an error when the request method is unsupported, the authority
     /// or host header is missing, or no matching fetch configuration is found.
+    /// A matched fetch with a `fault` returns its `FaultKind` as the error.
     pub fn fetch<T>(&self, request: &http::Request<T>) -> anyhow::Result<http::Response<Bytes>> {
+        let (response, _params) = self.fetch_with_params(request)?;
+        Ok(response)
+    }
+
+    /// Simulate fetching a request, returning the response along with any
+    /// named parameters captured from the matched fetch configuration's path
+    /// template.
+    ///
+    /// Fetch configurations with `match_body` set never match through this
+    /// method, since it cannot read the request body; use
+    /// `Fetcher::fetch_bytes_with_params` for those.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the request method is unsupported, the authority
+    /// or host header is missing, no matching fetch configuration is found,
+    /// the matched fetch has a `fault` (returned as its `FaultKind`), or the
+    /// matched response body cannot be encoded for its content type.
+    pub fn fetch_with_params<T>(
+        &self,
+        request: &http::Request<T>,
+    ) -> anyhow::Result<(http::Response<Bytes>, BTreeMap<String, String>)> {
+        self.match_request(request, None)
+    }
+
+    /// Simulate fetching a request whose body is available for structural
+    /// matching against a fetch's `match_body` expectation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the request method is unsupported, the authority
+    /// or host header is missing, no matching fetch configuration is found,
+    /// the matched fetch has a `fault` (returned as its `FaultKind`), or the
+    /// matched response body cannot be encoded for its content type.
+    pub fn fetch_bytes(&self, request: &http::Request<Bytes>) -> anyhow::Result<http::Response<Bytes>> {
+        let (response, _params) = self.fetch_bytes_with_params(request)?;
+        Ok(response)
+    }
+
+    /// Simulate fetching a request whose body is available for structural
+    /// matching, returning the response along with any named parameters
+    /// captured from the matched fetch configuration's path template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the request method is unsupported, the authority
+    /// or host header is missing, no matching fetch configuration is found,
+    /// the matched fetch has a `fault` (returned as its `FaultKind`), or the
+    /// matched response body cannot be encoded for its content type.
+    pub fn fetch_bytes_with_params(
+        &self,
+        request: &http::Request<Bytes>,
+    ) -> anyhow::Result<(http::Response<Bytes>, BTreeMap<String, String>)> {
+        let body = request.body().clone();
+        self.match_request(request, Some(&body))
+    }
+
+    /// Shared matching logic for `fetch_with_params` and
+    /// `fetch_bytes_with_params`. `body` is `Some` only when the caller has
+    /// read request body bytes available for structural comparison.
+    fn match_request<T>(
+        &self,
+        request: &http::Request<T>,
+        body: Option<&[u8]>,
+    ) -> anyhow::Result<(http::Response<Bytes>, BTreeMap<String, String>)> {
         let method = match *request.method() {
             http::Method::GET => Method::GET,
             http::Method::POST => Method::POST,
@@ -131,25 +556,314 @@ impl Fetcher {
             .ok_or_else(|| anyhow!("request missing authority or host header"))?;
 
         let path = request.uri().path().to_owned();
-        let request_id = request.uri().query().map(str::to_owned);
+        let query = request.uri().query().unwrap_or_default();
 
-        let fetch = self.fetches.iter().find(|candidate| {
-            candidate.authority == authority
-                && candidate.method == method
-                && candidate.path == path
-                && candidate.request == request_id
+        let matched = self.fetches.iter().find_map(|candidate| {
+            if candidate.authority != authority || candidate.method != method {
+                return None;
+            }
+            let params = match_path(&candidate.path, &path)?;
+            if !queries_match(candidate.request.as_deref(), query) {
+                return None;
+            }
+            if !headers_match(candidate.headers.as_ref(), request.headers()) {
+                return None;
+            }
+            if !body_matches(candidate.match_body.as_ref(), body) {
+                return None;
+            }
+            Some((candidate, params))
         });
 
-        let fetch = fetch.ok_or_else(|| {
+        let (fetch, params) = matched.ok_or_else(|| {
             anyhow!(
-                "no fetch configured for method={method:?}, authority={authority}, path={path}, request={request_id:?}"
+                "no fetch configured for method={method:?}, authority={authority}, path={path}, query={query:?}"
             )
         })?;
 
-        let status = fetch.response.status;
-        let body = Bytes::from(fetch.response.body.to_string());
+        let call_index = fetch.call_count.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(fault) = &fetch.fault {
+            if let Some(delay) = fault.delay {
+                std::thread::sleep(delay);
+            }
+            return Err(anyhow::Error::new(fault.kind));
+        }
+
+        let response_config = fetch.response.at(call_index);
+        if let Some(delay) = response_config.delay {
+            std::thread::sleep(delay);
+        }
+
+        let status = response_config.status;
+        let body = encode_body(&response_config.content_type, &response_config.body)?;
+
+        let mut builder = http::Response::builder().status(status);
+        let has_explicit_content_type =
+            response_config.headers.keys().any(|name| name.eq_ignore_ascii_case("content-type"));
+        if !has_explicit_content_type {
+            builder = builder.header(http::header::CONTENT_TYPE, response_config.content_type.as_str());
+        }
+        for (name, value) in &response_config.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder.body(body).map_err(anyhow::Error::new)?;
+        Ok((response, params))
+    }
+
+    /// Verify that every fetch with `min_calls` or `max_calls` expectations
+    /// was matched within its expected range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first fetch whose call count fell outside
+    /// its expected range.
+    pub fn verify(&self) -> anyhow::Result<()> {
+        for fetch in &self.fetches {
+            let calls = fetch.call_count.load(Ordering::Relaxed);
+            if fetch.min_calls.is_some_and(|min_calls| calls < min_calls)
+                || fetch.max_calls.is_some_and(|max_calls| calls > max_calls)
+            {
+                return Err(anyhow!(
+                    "fetch for method={:?}, authority={}, path={} was called {calls} time(s), expected min_calls={:?}, max_calls={:?}",
+                    fetch.method, fetch.authority, fetch.path, fetch.min_calls, fetch.max_calls
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check whether every header required by a fetch is present on the incoming
+/// request with a matching value. Header names are compared
+/// case-insensitively, as `http::HeaderMap` does internally; values must
+/// match exactly.
+fn headers_match(expected: Option<&BTreeMap<String, String>>, actual: &http::HeaderMap) -> bool {
+    let Some(expected) = expected else {
+        return true;
+    };
+    expected.iter().all(|(name, value)| {
+        actual.get(name.as_str()).is_some_and(|actual_value| actual_value == value.as_str())
+    })
+}
+
+/// Match a request path against a fetch's path template, returning the
+/// captured `{name}` parameters on success.
+///
+/// Literal segments must compare equal, a `{name}` segment matches any single
+/// non-empty segment, and a trailing `*` segment matches zero or more
+/// remaining segments.
+fn match_path(template: &str, path: &str) -> Option<BTreeMap<String, String>> {
+    let template_segments: Vec<&str> = template.split('/').collect();
+    let path_segments: Vec<&str> = path.split('/').collect();
+
+    let mut params = BTreeMap::new();
+    let mut path_index = 0;
+
+    for (template_index, segment) in template_segments.iter().enumerate() {
+        if *segment == "*" && template_index == template_segments.len() - 1 {
+            return Some(params);
+        }
+
+        let path_segment = path_segments.get(path_index)?;
+        if let Some(name) = segment.strip_prefix('{').and_then(|rest| rest.strip_suffix('}')) {
+            if path_segment.is_empty() {
+                return None;
+            }
+            params.insert(name.to_string(), (*path_segment).to_string());
+        } else if segment != path_segment {
+            return None;
+        }
+        path_index += 1;
+    }
+
+    (path_index == path_segments.len()).then_some(params)
+}
+
+/// Check whether a fetch's expected query string matches a request's query
+/// string, treating both as an order-insensitive set of key/value pairs.
+fn queries_match(expected: Option<&str>, actual: &str) -> bool {
+    match expected {
+        None => true,
+        Some(expected) => parse_query(expected) == parse_query(actual),
+    }
+}
+
+/// Parse a query string into a map of keys to their (sorted) list of values,
+/// percent-decoding keys and values along the way.
+fn parse_query(query: &str) -> BTreeMap<String, Vec<String>> {
+    let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        map.entry(percent_decode(key)).or_default().push(percent_decode(value));
+    }
+    for values in map.values_mut() {
+        values.sort();
+    }
+    map
+}
+
+/// Percent-decode a query string component, treating `+` as a space.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            b'%' => {
+                let hex = bytes
+                    .get(index + 1..index + 3)
+                    .and_then(|digits| std::str::from_utf8(digits).ok())
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+                match hex {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        index += 3;
+                    }
+                    None => {
+                        decoded.push(bytes[index]);
+                        index += 1;
+                    }
+                }
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Ergonomic builder for `http::Request<Bytes>` fixtures to feed to a
+/// `Fetcher` or a handler under test, so call sites don't need to hand-build
+/// requests with the `http` crate directly.
+pub struct TestRequest {
+    method: http::Method,
+    uri: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+}
+
+impl TestRequest {
+    fn new(method: http::Method, uri: impl Into<String>) -> Self {
+        Self { method, uri: uri.into(), query: Vec::new(), headers: Vec::new(), body: Bytes::new() }
+    }
+
+    /// Start building a GET request.
+    #[must_use]
+    pub fn get(uri: impl Into<String>) -> Self {
+        Self::new(http::Method::GET, uri)
+    }
+
+    /// Start building a POST request.
+    #[must_use]
+    pub fn post(uri: impl Into<String>) -> Self {
+        Self::new(http::Method::POST, uri)
+    }
+
+    /// Start building a PUT request.
+    #[must_use]
+    pub fn put(uri: impl Into<String>) -> Self {
+        Self::new(http::Method::PUT, uri)
+    }
+
+    /// Start building a DELETE request.
+    #[must_use]
+    pub fn delete(uri: impl Into<String>) -> Self {
+        Self::new(http::Method::DELETE, uri)
+    }
+
+    /// Start building a PATCH request.
+    #[must_use]
+    pub fn patch(uri: impl Into<String>) -> Self {
+        Self::new(http::Method::PATCH, uri)
+    }
+
+    /// Add a header to the request, replacing any existing header of the
+    /// same name (case-insensitively) such as the `content-type` set by
+    /// `json` or `form` if given afterwards.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Append query parameters to the request's URI.
+    #[must_use]
+    pub fn query(mut self, pairs: &[(&str, &str)]) -> Self {
+        self.query.extend(pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())));
+        self
+    }
+
+    /// Serialize `value` as the JSON request body and set a matching
+    /// `content-type` header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` cannot be serialized as JSON.
+    #[must_use]
+    pub fn json<T: Serialize>(mut self, value: &T) -> Self {
+        self.body = Bytes::from(serde_json::to_vec(value).expect("value should serialize as JSON"));
+        self.headers.push(("content-type".to_string(), ContentType::Json.as_str().to_string()));
+        self
+    }
+
+    /// Serialize `value` as a form-urlencoded request body and set a
+    /// matching `content-type` header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` cannot be serialized as a form body.
+    #[must_use]
+    pub fn form<T: Serialize>(mut self, value: &T) -> Self {
+        let encoded =
+            serde_urlencoded::to_string(value).expect("value should serialize as a form body");
+        self.body = Bytes::from(encoded.into_bytes());
+        self.headers.push(("content-type".to_string(), ContentType::Form.as_str().to_string()));
+        self
+    }
+
+    /// Build the finished `http::Request<Bytes>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the accumulated method, URI, headers, or body do not form a
+    /// well-formed request.
+    #[must_use]
+    pub fn finish(self) -> http::Request<Bytes> {
+        let uri = if self.query.is_empty() {
+            self.uri
+        } else {
+            let query = serde_urlencoded::to_string(&self.query)
+                .expect("query pairs should serialize as a query string");
+            let separator = if self.uri.contains('?') { '&' } else { '?' };
+            format!("{}{separator}{query}", self.uri)
+        };
+
+        let mut builder = http::Request::builder().method(self.method).uri(uri);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder.body(self.body).expect("test request should be well-formed")
+    }
 
-        http::Response::builder().status(status).body(body).map_err(anyhow::Error::new)
+    /// Build the request and send it to `fetcher`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no configured `Fetch` matches the built request,
+    /// or when `Fetcher::fetch_bytes` otherwise fails (e.g. the matched
+    /// `Fetch` has a `fault`).
+    pub fn send_to(self, fetcher: &Fetcher) -> anyhow::Result<http::Response<Bytes>> {
+        fetcher.fetch_bytes(&self.finish())
     }
 }
 
@@ -185,8 +899,9 @@ mod tests {
         assert_eq!(fetch.authority, "example.com");
         assert_eq!(fetch.method, Method::GET);
         assert_eq!(fetch.path, "/api/data");
-        assert_eq!(fetch.response.status, 404);
-        assert_eq!(fetch.response.body, "Not Found");
+        let response = fetch.response.at(0);
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "Not Found");
     }
 
     #[test]
@@ -196,8 +911,9 @@ mod tests {
         assert_eq!(fetch.authority, "example.com");
         assert_eq!(fetch.method, Method::GET);
         assert_eq!(fetch.path, "/");
-        assert_eq!(fetch.response.status, 200);
-        assert_eq!(fetch.response.body, "");
+        let response = fetch.response.at(0);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "");
     }
 
     #[test]
@@ -215,8 +931,9 @@ mod tests {
         assert_eq!(fetch.authority, "example.com");
         assert_eq!(fetch.method, Method::GET);
         assert_eq!(fetch.path, "/allocations/trips");
-        assert_eq!(fetch.response.status, 200);
-        assert_eq!(fetch.response.body, "[\"vehicle 1\"]");
+        let response = fetch.response.at(0);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "[\"vehicle 1\"]");
     }
 
     #[test]
@@ -226,7 +943,13 @@ mod tests {
             method: Method::GET,
             path: "/data".to_string(),
             request: Some("q=42".to_string()),
-            response: Response { status: 201, body: json!({"value": 42}) },
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response { status: 201, body: json!({"value": 42}), content_type: ContentType::Json, headers: BTreeMap::new(), delay: None }),
         };
         let fetcher = Fetcher::new(&[fetch]);
 
@@ -244,7 +967,13 @@ mod tests {
             method: Method::GET,
             path: "/allocations".to_string(),
             request: Some("vehicle=1".to_string()),
-            response: Response { status: 200, body: json!([1]) },
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response { status: 200, body: json!([1]), content_type: ContentType::Json, headers: BTreeMap::new(), delay: None }),
         };
         let fetcher = Fetcher::new(&[fetch]);
 
@@ -264,4 +993,559 @@ mod tests {
         let error = fetcher.fetch(&request).expect_err("should fail without mock");
         assert!(error.to_string().contains("no fetch configured"));
     }
+
+    #[test]
+    fn fetcher_matches_path_template_params() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/users/{id}/orders/{order_id}".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response { status: 200, body: json!({"ok": true}), content_type: ContentType::Json, headers: BTreeMap::new(), delay: None }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request =
+            build_request(http::Method::GET, "https://example.com/users/42/orders/7", None);
+        let (response, params) =
+            fetcher.fetch_with_params(&request).expect("should match template");
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+        assert_eq!(params.get("order_id"), Some(&"7".to_string()));
+    }
+
+    #[test]
+    fn fetcher_matches_path_wildcard() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/assets/*".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request =
+            build_request(http::Method::GET, "https://example.com/assets/css/app.css", None);
+        fetcher.fetch(&request).expect("wildcard should match trailing segments");
+    }
+
+    #[test]
+    fn fetcher_matches_query_order_insensitive() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/data".to_string(),
+            request: Some("a=1&b=2".to_string()),
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request =
+            build_request(http::Method::GET, "https://example.com/data?b=2&a=1", None);
+        fetcher.fetch(&request).expect("reordered query should still match");
+    }
+
+    #[test]
+    fn percent_decode_does_not_panic_on_malformed_escape_near_multibyte_char() {
+        // A `%` not followed by two hex digits must fall back to a literal
+        // `%` instead of panicking when it slices a char boundary, e.g. when
+        // it abuts a multi-byte UTF-8 character.
+        assert_eq!(percent_decode("abc%€x"), "abc%€x");
+        assert_eq!(percent_decode("abc%"), "abc%");
+        assert_eq!(percent_decode("abc%a"), "abc%a");
+    }
+
+    #[test]
+    fn fetcher_matches_required_header() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/secure".to_string(),
+            request: None,
+            headers: Some(BTreeMap::from([("Authorization".to_string(), "Bearer t0k3n".to_string())])),
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let mut builder = http::Request::builder().method(http::Method::GET).uri("https://example.com/secure");
+        builder = builder.header("authorization", "Bearer t0k3n");
+        let request = builder.body(()).expect("should build request");
+
+        fetcher.fetch(&request).expect("should match on required header");
+    }
+
+    #[test]
+    fn fetcher_rejects_mismatched_header() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/secure".to_string(),
+            request: None,
+            headers: Some(BTreeMap::from([("Authorization".to_string(), "Bearer t0k3n".to_string())])),
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/secure", None);
+        let error = fetcher.fetch(&request).expect_err("missing header should not match");
+        assert!(error.to_string().contains("no fetch configured"));
+    }
+
+    #[test]
+    fn fetcher_injects_response_headers() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/data".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response {
+                status: 200,
+                body: Value::String(String::new()),
+                content_type: ContentType::Json,
+                headers: BTreeMap::from([("Content-Type".to_string(), "text/plain".to_string())]),
+                delay: None,
+            }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/data", None);
+        let response = fetcher.fetch(&request).expect("should find mock fetch");
+
+        assert_eq!(response.headers().get("content-type").expect("header set"), "text/plain");
+    }
+
+    #[test]
+    fn fetcher_defaults_content_type_header_to_json() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/data".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response { status: 200, body: json!({"ok": true}), ..Response::default() }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/data", None);
+        let response = fetcher.fetch(&request).expect("should find mock fetch");
+
+        assert_eq!(
+            response.headers().get("content-type").expect("header set"),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn fetcher_emits_plain_text_body_verbatim() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/message".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response {
+                status: 200,
+                body: Value::String("Not Found".to_string()),
+                content_type: ContentType::Text,
+                headers: BTreeMap::new(),
+                delay: None,
+            }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/message", None);
+        let response = fetcher.fetch(&request).expect("should find mock fetch");
+
+        assert_eq!(response.body(), &Bytes::from("Not Found"));
+        assert_eq!(response.headers().get("content-type").expect("header set"), "text/plain");
+    }
+
+    #[test]
+    fn fetcher_emits_form_encoded_body() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/form".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response {
+                status: 200,
+                body: json!({"name": "ferris"}),
+                content_type: ContentType::Form,
+                headers: BTreeMap::new(),
+                delay: None,
+            }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/form", None);
+        let response = fetcher.fetch(&request).expect("should find mock fetch");
+
+        assert_eq!(response.body(), &Bytes::from("name=ferris"));
+    }
+
+    #[test]
+    fn fetcher_emits_base64_decoded_bytes() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/binary".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Single(Response {
+                status: 200,
+                body: Value::String("aGVsbG8=".to_string()),
+                content_type: ContentType::Bytes,
+                headers: BTreeMap::new(),
+                delay: None,
+            }),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::GET, "https://example.com/binary", None);
+        let response = fetcher.fetch(&request).expect("should find mock fetch");
+
+        assert_eq!(response.body(), &Bytes::from("hello"));
+    }
+
+    fn build_bytes_request(method: http::Method, uri: &str, body: &str) -> http::Request<Bytes> {
+        http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Bytes::from(body.to_string()))
+            .expect("should build request")
+    }
+
+    #[test]
+    fn fetcher_matches_json_request_body_regardless_of_key_order() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::POST,
+            path: "/orders".to_string(),
+            request: None,
+            headers: None,
+            match_body: Some(MatchBody {
+                content_type: ContentType::Json,
+                value: json!({"item": "widget", "quantity": 3}),
+            }),
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_bytes_request(
+            http::Method::POST,
+            "https://example.com/orders",
+            r#"{"quantity":3,"item":"widget"}"#,
+        );
+        fetcher.fetch_bytes(&request).expect("reordered JSON body should still match");
+    }
+
+    #[test]
+    fn fetcher_matches_form_request_body() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::POST,
+            path: "/orders".to_string(),
+            request: None,
+            headers: None,
+            match_body: Some(MatchBody {
+                content_type: ContentType::Form,
+                value: json!({"item": "widget", "quantity": "3"}),
+            }),
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_bytes_request(
+            http::Method::POST,
+            "https://example.com/orders",
+            "quantity=3&item=widget",
+        );
+        fetcher.fetch_bytes(&request).expect("reordered form body should still match");
+    }
+
+    #[test]
+    fn fetcher_rejects_mismatched_request_body() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::POST,
+            path: "/orders".to_string(),
+            request: None,
+            headers: None,
+            match_body: Some(MatchBody {
+                content_type: ContentType::Json,
+                value: json!({"item": "widget"}),
+            }),
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_bytes_request(
+            http::Method::POST,
+            "https://example.com/orders",
+            r#"{"item":"gadget"}"#,
+        );
+        let error =
+            fetcher.fetch_bytes(&request).expect_err("mismatched body should not match");
+        assert!(error.to_string().contains("no fetch configured"));
+    }
+
+    #[test]
+    fn fetcher_plain_fetch_ignores_match_body_fixtures() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::POST,
+            path: "/orders".to_string(),
+            request: None,
+            headers: None,
+            match_body: Some(MatchBody {
+                content_type: ContentType::Json,
+                value: json!({"item": "widget"}),
+            }),
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::default(),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let request = build_request(http::Method::POST, "https://example.com/orders", None);
+        let error = fetcher.fetch(&request).expect_err("fetch without a body cannot match");
+        assert!(error.to_string().contains("no fetch configured"));
+    }
+
+    #[test]
+    fn fetcher_advances_through_sequential_responses() {
+        let fetch = Fetch {
+            authority: "example.com".to_string(),
+            method: Method::GET,
+            path: "/status".to_string(),
+            request: None,
+            headers: None,
+            match_body: None,
+            min_calls: None,
+            max_calls: None,
+            fault: None,
+            call_count: AtomicUsize::new(0),
+            response: Responses::Sequence(vec![
+                Response { status: 503, ..Response::default() },
+                Response { status: 200, ..Response::default() },
+            ]),
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/status", None);
+
+        let first = fetcher.fetch(&request).expect("should match first call");
+        assert_eq!(first.status(), 503);
+
+        let second = fetcher.fetch(&request).expect("should match second call");
+        assert_eq!(second.status(), 200);
+
+        let third = fetcher.fetch(&request).expect("should repeat last entry once exhausted");
+        assert_eq!(third.status(), 200);
+    }
+
+    #[test]
+    fn fetcher_fault_returns_connection_refused_error() {
+        let fetch = Fetch {
+            path: "/flaky".to_string(),
+            fault: Some(Fault { kind: FaultKind::ConnectionRefused, delay: None }),
+            ..Fetch::default()
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/flaky", None);
+
+        let error = fetcher.fetch(&request).expect_err("fault should surface as an error");
+        assert_eq!(error.downcast_ref::<FaultKind>(), Some(&FaultKind::ConnectionRefused));
+    }
+
+    #[test]
+    fn fetcher_fault_honors_delay_before_returning_timeout() {
+        let fetch = Fetch {
+            path: "/slow".to_string(),
+            fault: Some(Fault {
+                kind: FaultKind::Timeout,
+                delay: Some(Duration::from_millis(5)),
+            }),
+            ..Fetch::default()
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/slow", None);
+
+        let started = std::time::Instant::now();
+        let error = fetcher.fetch(&request).expect_err("timeout fault should surface as an error");
+        assert!(started.elapsed() >= Duration::from_millis(5));
+        assert_eq!(error.downcast_ref::<FaultKind>(), Some(&FaultKind::Timeout));
+    }
+
+    #[test]
+    fn fetcher_response_delay_does_not_produce_an_error() {
+        let fetch = Fetch {
+            path: "/slow-ok".to_string(),
+            response: Responses::Single(Response {
+                delay: Some(Duration::from_millis(5)),
+                ..Response::default()
+            }),
+            ..Fetch::default()
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/slow-ok", None);
+
+        let started = std::time::Instant::now();
+        let response = fetcher.fetch(&request).expect("delayed response should still match");
+        assert!(started.elapsed() >= Duration::from_millis(5));
+        assert_eq!(response.status(), 200);
+    }
+
+    #[test]
+    fn fetcher_verify_fails_below_min_calls() {
+        let fetch = Fetch { path: "/ping".to_string(), min_calls: Some(1), ..Fetch::default() };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let error = fetcher.verify().expect_err("uncalled fetch should fail min_calls");
+        assert!(error.to_string().contains("expected min_calls=Some(1)"));
+    }
+
+    #[test]
+    fn fetcher_verify_fails_above_max_calls() {
+        let fetch = Fetch { path: "/ping".to_string(), max_calls: Some(1), ..Fetch::default() };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/ping", None);
+
+        fetcher.fetch(&request).expect("first call should match");
+        fetcher.fetch(&request).expect("fetch still matches beyond max_calls");
+
+        let error = fetcher.verify().expect_err("second call should exceed max_calls");
+        assert!(error.to_string().contains("expected min_calls=None, max_calls=Some(1)"));
+    }
+
+    #[test]
+    fn fetcher_verify_succeeds_within_call_count_range() {
+        let fetch = Fetch {
+            path: "/ping".to_string(),
+            min_calls: Some(1),
+            max_calls: Some(1),
+            ..Fetch::default()
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+        let request = build_request(http::Method::GET, "https://example.com/ping", None);
+
+        fetcher.fetch(&request).expect("should match");
+        fetcher.verify().expect("call count should satisfy min_calls and max_calls");
+    }
+
+    #[test]
+    fn test_request_builds_query_and_headers() {
+        let request = TestRequest::get("https://example.com/users/42")
+            .query(&[("active", "true")])
+            .header("x-request-id", "abc")
+            .finish();
+
+        assert_eq!(request.method(), http::Method::GET);
+        assert_eq!(request.uri(), "https://example.com/users/42?active=true");
+        assert_eq!(request.headers().get("x-request-id").unwrap(), "abc");
+    }
+
+    #[test]
+    fn test_request_json_sets_body_and_content_type() {
+        let request = TestRequest::post("https://example.com/orders").json(&json!({"item": "widget"})).finish();
+
+        assert_eq!(request.headers().get("content-type").unwrap(), "application/json");
+        assert_eq!(request.body().as_ref(), br#"{"item":"widget"}"#);
+    }
+
+    #[test]
+    fn test_request_form_sets_body_and_content_type() {
+        let request =
+            TestRequest::put("https://example.com/orders/1").form(&json!({"item": "widget"})).finish();
+
+        assert_eq!(request.headers().get("content-type").unwrap(), "application/x-www-form-urlencoded");
+        assert_eq!(request.body().as_ref(), b"item=widget");
+    }
+
+    #[test]
+    fn test_request_send_to_matches_configured_fetch() {
+        let fetch = Fetch {
+            method: Method::POST,
+            path: "/orders".to_string(),
+            response: Responses::Single(Response { status: 201, ..Response::default() }),
+            ..Fetch::default()
+        };
+        let fetcher = Fetcher::new(&[fetch]);
+
+        let response = TestRequest::post("https://example.com/orders")
+            .json(&json!({"item": "widget"}))
+            .send_to(&fetcher)
+            .expect("should match configured fetch");
+        assert_eq!(response.status(), 201);
+    }
 }